@@ -0,0 +1,83 @@
+use std::{net::AddrParseError, num::ParseIntError, str::ParseBoolError};
+use thiserror::Error;
+
+/// Errors that can occur while loading or parsing a Leptos configuration.
+#[derive(Error, Debug, Clone)]
+#[non_exhaustive]
+pub enum LeptosConfigError {
+    /// The configuration file could not be found or read.
+    #[error("couldn't find the config file")]
+    ConfigNotFound,
+    /// Neither a `[package.metadata.leptos]` nor a
+    /// `[[workspace.metadata.leptos]]` section could be found in the file.
+    #[error(
+        "couldn't find a [package.metadata.leptos] or \
+         [[workspace.metadata.leptos]] section in the given file"
+    )]
+    ConfigSectionNotFound,
+    /// A standalone config file (`leptos.toml`/`.json`/`.yaml`) was found
+    /// and parsed, but it has no top-level `leptos-options` key.
+    #[error(
+        "couldn't find a top-level `leptos-options` key in the given \
+         config file"
+    )]
+    MissingLeptosOptionsKey,
+    /// The path given for a standalone config file has an extension that
+    /// doesn't match any supported format (`toml`, `json`, `yaml`/`yml`).
+    /// This is checked before the file is read.
+    #[error(
+        "unsupported config file extension {0:?}; expected one of `toml`, \
+         `json`, `yaml`, `yml`"
+    )]
+    UnsupportedConfigFormat(String),
+    /// The underlying `config` crate failed to build or deserialize the
+    /// configuration.
+    #[error("error parsing config: {0}")]
+    ConfigError(String),
+    /// An environment variable was set to a value that could not be parsed.
+    #[error("{0}")]
+    EnvVarError(String),
+    /// Multiple `[[workspace.metadata.leptos]]` entries were found and no
+    /// project name was given to disambiguate between them.
+    #[error(
+        "multiple Leptos projects found in this workspace; pass one of \
+         {0:?} to select one"
+    )]
+    AmbiguousProject(Vec<String>),
+    /// A project name was given to select a
+    /// `[[workspace.metadata.leptos]]` entry, but it didn't match any of
+    /// the available entries.
+    #[error(
+        "no Leptos project named {0:?} found in this workspace; available \
+         projects are {1:?}"
+    )]
+    ProjectNotFound(String, Vec<String>),
+    /// A `--leptos-*` command-line argument didn't match any known
+    /// [LeptosOptions] field.
+    #[error("unknown argument `--leptos-{0}`; valid keys are {1:?}")]
+    UnknownArg(String, Vec<String>),
+}
+
+impl From<AddrParseError> for LeptosConfigError {
+    fn from(e: AddrParseError) -> Self {
+        LeptosConfigError::EnvVarError(e.to_string())
+    }
+}
+
+impl From<ParseIntError> for LeptosConfigError {
+    fn from(e: ParseIntError) -> Self {
+        LeptosConfigError::EnvVarError(e.to_string())
+    }
+}
+
+impl From<ParseBoolError> for LeptosConfigError {
+    fn from(e: ParseBoolError) -> Self {
+        LeptosConfigError::EnvVarError(e.to_string())
+    }
+}
+
+impl From<config::ConfigError> for LeptosConfigError {
+    fn from(e: config::ConfigError) -> Self {
+        LeptosConfigError::ConfigError(e.to_string())
+    }
+}
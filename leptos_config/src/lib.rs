@@ -299,26 +299,54 @@ impl TryFrom<String> for ReloadWSProtocol {
     }
 }
 
-/// Loads [LeptosOptions] from a Cargo.toml text content with layered overrides.
-/// If an env var is specified, like `LEPTOS_ENV`, it will override a setting in the file.
-pub fn get_config_from_str(
+/// Finds the `[package.metadata.leptos.<env_key>]` sub-table for the given
+/// environment key (e.g. `"dev"` or `"prod"`) within `text` and returns its
+/// body (without the header), padded with leading newlines so that any
+/// serde error still reports the right line number. Returns `None` if no
+/// such sub-table exists - unknown/absent sub-tables are simply ignored.
+fn find_env_subtable(text: &str, env_key: &str) -> Option<String> {
+    let header = format!("[package.metadata.leptos.{env_key}]");
+    let re = Regex::new(&format!(r"(?m)^{}", regex::escape(&header))).unwrap();
+    let found = re.find(text)?;
+
+    let body_start = found.end();
+    let rest = &text[body_start..];
+    let body_end = Regex::new(r"(?m)^\[")
+        .unwrap()
+        .find(rest)
+        .map(|m| m.start())
+        .unwrap_or(rest.len());
+
+    let newlines = text[..found.start()].matches('\n').count();
+    Some("\n".repeat(newlines) + &rest[..body_end])
+}
+
+/// Builds the base + per-environment-sub-table layers of the `Config` for
+/// the `[package.metadata.leptos]` (or `[[workspace.metadata.leptos]]`)
+/// section found in `text`. Shared by [get_config_from_str] and the
+/// argv-layering in [get_configuration_with_args].
+fn base_config_builder(
     text: &str,
-) -> Result<LeptosOptions, LeptosConfigError> {
+) -> Result<config::ConfigBuilder<config::builder::DefaultState>, LeptosConfigError>
+{
     let re: Regex = Regex::new(r"(?m)^\[package.metadata.leptos\]").unwrap();
     let re_workspace: Regex =
         Regex::new(r"(?m)^\[\[workspace.metadata.leptos\]\]").unwrap();
 
     let metadata_name;
     let start;
+    let is_package_table;
     match re.find(text) {
         Some(found) => {
             metadata_name = "[package.metadata.leptos]";
             start = found.start();
+            is_package_table = true;
         }
         None => match re_workspace.find(text) {
             Some(found) => {
                 metadata_name = "[[workspace.metadata.leptos]]";
                 start = found.start();
+                is_package_table = false;
             }
             None => return Err(LeptosConfigError::ConfigSectionNotFound),
         },
@@ -329,12 +357,136 @@ pub fn get_config_from_str(
     let input = "\n".repeat(newlines) + &text[start..];
     // so the settings will be interpreted as root level settings
     let toml = input.replace(metadata_name, "");
-    let settings = Config::builder()
+
+    let mut builder = Config::builder()
         // Read the "default" configuration file
-        .add_source(File::from_str(&toml, FileFormat::Toml))
-        // Layer on the environment-specific values.
-        // Add in settings from environment variables (with a prefix of LEPTOS)
-        // E.g. `LEPTOS_RELOAD_PORT=5001 would set `LeptosOptions.reload_port`
+        .add_source(File::from_str(&toml, FileFormat::Toml));
+
+    // Layer the per-environment sub-table (if any) on top of the base
+    // table. Only the `[package.metadata.leptos]` layout supports this -
+    // array-of-tables workspace entries don't have an addressable header
+    // to nest a sub-table under.
+    if is_package_table {
+        let env = env_from_str(env_w_default("LEPTOS_ENV", "DEV")?.as_str())?;
+        let env_key = match env {
+            Env::DEV => "dev",
+            Env::PROD => "prod",
+        };
+        if let Some(sub_toml) = find_env_subtable(text, env_key) {
+            builder =
+                builder.add_source(File::from_str(&sub_toml, FileFormat::Toml));
+        }
+    }
+
+    Ok(builder)
+}
+
+/// Loads [LeptosOptions] from a Cargo.toml text content with layered overrides.
+/// The layers, from lowest to highest priority, are:
+/// 1. the base `[package.metadata.leptos]` (or `[[workspace.metadata.leptos]]`) table,
+/// 2. the `[package.metadata.leptos.dev]`/`[package.metadata.leptos.prod]` sub-table
+///    matching the active [Env] (resolved from `LEPTOS_ENV`, defaulting to `DEV`),
+/// 3. environment variables, e.g. `LEPTOS_RELOAD_PORT=5001` overrides `reload_port`.
+pub fn get_config_from_str(
+    text: &str,
+) -> Result<LeptosOptions, LeptosConfigError> {
+    // Add in settings from environment variables (with a prefix of LEPTOS)
+    // E.g. `LEPTOS_RELOAD_PORT=5001 would set `LeptosOptions.reload_port`
+    let settings = base_config_builder(text)?
+        .add_source(
+            config::Environment::with_prefix("LEPTOS")
+                .convert_case(Case::Kebab),
+        )
+        .build()?;
+
+    settings
+        .try_deserialize()
+        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))
+}
+
+/// Finds every `[[workspace.metadata.leptos]]` array-of-tables entry in
+/// `text` and returns the body (without the header) of each, padded with
+/// leading newlines so that any serde error still reports the right line
+/// number.
+fn find_workspace_tables(text: &str) -> Vec<String> {
+    let header_re =
+        Regex::new(r"(?m)^\[\[workspace\.metadata\.leptos\]\]").unwrap();
+    let next_header_re = Regex::new(r"(?m)^\[").unwrap();
+
+    header_re
+        .find_iter(text)
+        .map(|found| {
+            let body_start = found.end();
+            let rest = &text[body_start..];
+            let body_end = next_header_re
+                .find(rest)
+                .map(|m| m.start())
+                .unwrap_or(rest.len());
+            let newlines = text[..found.start()].matches('\n').count();
+            "\n".repeat(newlines) + &rest[..body_end]
+        })
+        .collect()
+}
+
+/// Like [get_config_from_str], but for workspaces that define more than one
+/// `[[workspace.metadata.leptos]]` project. Each entry is matched against
+/// `project` by its `name` key, or by its `bin-package` key when it has no
+/// `name`; if `project` is `None` and
+/// exactly one entry exists, that one is used. If no project name is given
+/// and multiple entries exist, [LeptosConfigError::AmbiguousProject] is
+/// returned listing the available names. If a project name is given but
+/// doesn't match any entry, [LeptosConfigError::ProjectNotFound] is
+/// returned instead, listing the available names.
+pub fn get_config_from_str_for_project(
+    text: &str,
+    project: Option<&str>,
+) -> Result<LeptosOptions, LeptosConfigError> {
+    let re: Regex = Regex::new(r"(?m)^\[package.metadata.leptos\]").unwrap();
+    if re.find(text).is_some() {
+        // Single package table - no project selection to do.
+        return get_config_from_str(text);
+    }
+
+    let entries = find_workspace_tables(text);
+    if entries.is_empty() {
+        return Err(LeptosConfigError::ConfigSectionNotFound);
+    }
+
+    let mut named = Vec::with_capacity(entries.len());
+    for body in entries {
+        let settings = Config::builder()
+            .add_source(File::from_str(&body, FileFormat::Toml))
+            .build()?;
+        let name = settings
+            .get_string("name")
+            .or_else(|_| settings.get_string("bin-package"))
+            .ok();
+        named.push((name, body));
+    }
+
+    let selected = match project {
+        Some(project) => named
+            .iter()
+            .find(|(name, _)| name.as_deref() == Some(project))
+            .map(|(_, body)| body.clone())
+            .ok_or_else(|| {
+                LeptosConfigError::ProjectNotFound(
+                    project.to_string(),
+                    named.iter().filter_map(|(name, _)| name.clone()).collect(),
+                )
+            })?,
+        None => match named.as_slice() {
+            [(_, body)] => body.clone(),
+            _ => {
+                return Err(LeptosConfigError::AmbiguousProject(
+                    named.iter().filter_map(|(name, _)| name.clone()).collect(),
+                ))
+            }
+        },
+    };
+
+    let settings = Config::builder()
+        .add_source(File::from_str(&selected, FileFormat::Toml))
         .add_source(
             config::Environment::with_prefix("LEPTOS")
                 .convert_case(Case::Kebab),
@@ -361,6 +513,98 @@ pub fn get_configuration(
     }
 }
 
+/// Like [get_configuration], but for workspaces that build more than one
+/// Leptos project from a single `Cargo.toml`. See
+/// [get_config_from_str_for_project] for how `project` is matched.
+pub fn get_configuration_for_project(
+    path: Option<&str>,
+    project: Option<&str>,
+) -> Result<ConfFile, LeptosConfigError> {
+    if let Some(path) = path {
+        let text = fs::read_to_string(path)
+            .map_err(|_| LeptosConfigError::ConfigNotFound)?;
+        Ok(ConfFile {
+            leptos_options: get_config_from_str_for_project(&text, project)?,
+        })
+    } else {
+        get_config_from_env()
+    }
+}
+
+/// The kebab-case keys accepted by `--leptos-<key>=<value>` arguments in
+/// [get_configuration_with_args], matching the fields of [LeptosOptions].
+const VALID_ARG_KEYS: &[&str] = &[
+    "output-name",
+    "site-root",
+    "site-pkg-dir",
+    "env",
+    "site-addr",
+    "reload-port",
+    "reload-external-port",
+    "reload-ws-protocol",
+    "not-found-path",
+    "hash-file",
+    "hash-files",
+];
+
+/// Loads [LeptosOptions] from an optional config file, then layers
+/// `LEPTOS_`-prefixed environment variables on top, then layers
+/// `--leptos-<key>=<value>` command-line arguments on top of that, so
+/// arguments win over environment variables, which win over the file.
+/// Recognized arguments look like `--leptos-site-addr=0.0.0.0:8080` or
+/// `--leptos-reload-port=4001`; any argument that doesn't start with
+/// `--leptos-` is ignored, so callers can pass their whole
+/// `std::env::args()`. An unrecognized `--leptos-*` key returns
+/// [LeptosConfigError::UnknownArg] listing the valid keys.
+pub fn get_configuration_with_args(
+    path: Option<&str>,
+    args: &[String],
+) -> Result<ConfFile, LeptosConfigError> {
+    let mut builder = match path {
+        Some(path) => {
+            let text = fs::read_to_string(path)
+                .map_err(|_| LeptosConfigError::ConfigNotFound)?;
+            base_config_builder(&text)?
+        }
+        None => Config::builder(),
+    }
+    // Only this function needs a fallback for `output-name`: it's meant
+    // to be usable with no file and no `LEPTOS_OUTPUT_NAME` set, purely
+    // from CLI args. `set_default` is the lowest-priority source, so a
+    // file, the environment, or a `--leptos-output-name=` argument all
+    // still take precedence over it.
+    .set_default("output-name", default_output_name().to_string())?
+    .add_source(
+        config::Environment::with_prefix("LEPTOS").convert_case(Case::Kebab),
+    );
+
+    for arg in args {
+        let Some(rest) = arg.strip_prefix("--leptos-") else {
+            continue;
+        };
+        let Some((key, value)) = rest.split_once('=') else {
+            return Err(LeptosConfigError::UnknownArg(
+                rest.to_string(),
+                VALID_ARG_KEYS.iter().map(|s| s.to_string()).collect(),
+            ));
+        };
+        if !VALID_ARG_KEYS.contains(&key) {
+            return Err(LeptosConfigError::UnknownArg(
+                key.to_string(),
+                VALID_ARG_KEYS.iter().map(|s| s.to_string()).collect(),
+            ));
+        }
+        builder = builder.set_override(key, value.to_string())?;
+    }
+
+    let leptos_options = builder
+        .build()?
+        .try_deserialize()
+        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))?;
+
+    Ok(ConfFile { leptos_options })
+}
+
 /// Loads [LeptosOptions] from a Cargo.toml with layered overrides. Leptos will read in the settings itself. This
 /// option currently does not allow dashes in file or folder names, as all dashes become underscores
 pub fn get_config_from_file<P: AsRef<Path>>(
@@ -379,6 +623,69 @@ pub fn get_config_from_env() -> Result<ConfFile, LeptosConfigError> {
     })
 }
 
+/// Loads [LeptosOptions] from a standalone config file, such as
+/// `leptos.toml`, `leptos.json`, or `leptos.yaml`/`leptos.yml`, instead of
+/// scraping a `[package.metadata.leptos]` section out of `Cargo.toml`. The
+/// file format is picked from the path's extension, and the document is
+/// expected to have a top-level `leptos-options` table/object - the same
+/// shape [ConfFile] deserializes from. Environment variables (prefixed
+/// `LEPTOS_`) are layered on top, same as [get_config_from_file].
+pub fn get_config_from_config_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<ConfFile, LeptosConfigError> {
+    let path = path.as_ref();
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let format = match extension {
+        Some("toml") => FileFormat::Toml,
+        Some("json") => FileFormat::Json,
+        Some("yaml" | "yml") => FileFormat::Yaml,
+        _ => {
+            return Err(LeptosConfigError::UnsupportedConfigFormat(
+                extension.unwrap_or("").to_string(),
+            ))
+        }
+    };
+
+    let text = fs::read_to_string(path)
+        .map_err(|_| LeptosConfigError::ConfigNotFound)?;
+
+    let raw = Config::builder()
+        .add_source(File::from_str(&text, format))
+        .build()?;
+
+    // Pull out just the `leptos-options` document. Its entries aren't a
+    // `config::Source` on their own, so set each one as a per-key default
+    // (lowest priority) on a fresh builder, the same way
+    // [get_configuration_with_args] sets its `output-name` fallback - this way
+    // the environment-variable source below still layers on top of it,
+    // the same way it does for the `[package.metadata.leptos]` section
+    // in `Cargo.toml`.
+    let leptos_options: config::Value = raw
+        .get("leptos-options")
+        .map_err(|_| LeptosConfigError::MissingLeptosOptionsKey)?;
+
+    let mut builder = Config::builder();
+    for (key, value) in leptos_options
+        .into_table()
+        .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))?
+    {
+        builder = builder.set_default(key, value)?;
+    }
+
+    let settings = builder
+        .add_source(
+            config::Environment::with_prefix("LEPTOS")
+                .convert_case(Case::Kebab),
+        )
+        .build()?;
+
+    Ok(ConfFile {
+        leptos_options: settings
+            .try_deserialize()
+            .map_err(|e| LeptosConfigError::ConfigError(e.to_string()))?,
+    })
+}
+
 #[path = "tests.rs"]
 #[cfg(test)]
 mod tests;
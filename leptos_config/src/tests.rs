@@ -0,0 +1,230 @@
+use crate::*;
+
+fn default_options() -> LeptosOptions {
+    LeptosOptions::builder().build()
+}
+
+#[test]
+fn test_get_config_from_str_package_table() {
+    let text = r#"
+[package]
+name = "test"
+
+[package.metadata.leptos]
+output-name = "test"
+site-addr = "127.0.0.1:4000"
+"#;
+    let conf = get_config_from_str(text).unwrap();
+    assert_eq!(conf.output_name.as_ref(), "test");
+    assert_eq!(conf.site_addr, "127.0.0.1:4000".parse().unwrap());
+    assert_eq!(conf.reload_port, default_options().reload_port);
+}
+
+#[test]
+fn test_get_config_from_str_workspace_table() {
+    let text = r#"
+[[workspace.metadata.leptos]]
+output-name = "test"
+site-addr = "127.0.0.1:4000"
+"#;
+    let conf = get_config_from_str(text).unwrap();
+    assert_eq!(conf.output_name.as_ref(), "test");
+    assert_eq!(conf.site_addr, "127.0.0.1:4000".parse().unwrap());
+}
+
+#[test]
+fn test_get_config_from_str_missing_section() {
+    let text = r#"
+[package]
+name = "test"
+"#;
+    let err = get_config_from_str(text).unwrap_err();
+    assert!(matches!(err, LeptosConfigError::ConfigSectionNotFound));
+}
+
+#[test]
+fn test_get_config_from_str_env_subtable_overlay() {
+    let text = r#"
+[package.metadata.leptos]
+output-name = "test"
+site-addr = "127.0.0.1:3000"
+
+[package.metadata.leptos.dev]
+site-addr = "127.0.0.1:4000"
+
+[package.metadata.leptos.prod]
+site-addr = "0.0.0.0:80"
+"#;
+    // defaults to DEV when LEPTOS_ENV isn't set
+    let conf = get_config_from_str(text).unwrap();
+    assert_eq!(conf.site_addr, "127.0.0.1:4000".parse().unwrap());
+}
+
+#[test]
+fn test_get_config_from_str_for_project_selects_named_entry() {
+    let text = r#"
+[[workspace.metadata.leptos]]
+name = "app-one"
+output-name = "app-one"
+site-addr = "127.0.0.1:4000"
+
+[[workspace.metadata.leptos]]
+name = "app-two"
+output-name = "app-two"
+site-addr = "127.0.0.1:5000"
+"#;
+    let conf =
+        get_config_from_str_for_project(text, Some("app-two")).unwrap();
+    assert_eq!(conf.site_addr, "127.0.0.1:5000".parse().unwrap());
+}
+
+#[test]
+fn test_get_config_from_str_for_project_single_entry_without_name() {
+    let text = r#"
+[[workspace.metadata.leptos]]
+output-name = "test"
+site-addr = "127.0.0.1:4000"
+"#;
+    let conf = get_config_from_str_for_project(text, None).unwrap();
+    assert_eq!(conf.site_addr, "127.0.0.1:4000".parse().unwrap());
+}
+
+#[test]
+fn test_get_config_from_str_for_project_ambiguous_without_name() {
+    let text = r#"
+[[workspace.metadata.leptos]]
+name = "app-one"
+site-addr = "127.0.0.1:4000"
+
+[[workspace.metadata.leptos]]
+name = "app-two"
+site-addr = "127.0.0.1:5000"
+"#;
+    let err = get_config_from_str_for_project(text, None).unwrap_err();
+    assert!(matches!(err, LeptosConfigError::AmbiguousProject(_)));
+}
+
+#[test]
+fn test_get_config_from_str_for_project_unknown_name_errors() {
+    let text = r#"
+[[workspace.metadata.leptos]]
+name = "app-one"
+site-addr = "127.0.0.1:4000"
+"#;
+    let err =
+        get_config_from_str_for_project(text, Some("no-such-app")).unwrap_err();
+    assert!(matches!(
+        err,
+        LeptosConfigError::ProjectNotFound(name, _) if name == "no-such-app"
+    ));
+}
+
+#[test]
+fn test_get_configuration_with_args_overrides_defaults() {
+    let args = vec!["--leptos-site-addr=0.0.0.0:8080".to_string()];
+    let conf = get_configuration_with_args(None, &args).unwrap();
+    assert_eq!(
+        conf.leptos_options.site_addr,
+        "0.0.0.0:8080".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_get_configuration_with_args_ignores_unrelated_args() {
+    let args =
+        vec!["--some-other-flag=ignored".to_string(), "positional".to_string()];
+    let conf = get_configuration_with_args(None, &args).unwrap();
+    assert_eq!(conf.leptos_options.site_addr, default_options().site_addr);
+}
+
+#[test]
+fn test_get_configuration_with_args_unknown_key_errors() {
+    let args = vec!["--leptos-not-a-real-key=1".to_string()];
+    let err = get_configuration_with_args(None, &args).unwrap_err();
+    assert!(matches!(err, LeptosConfigError::UnknownArg(..)));
+}
+
+#[test]
+fn test_get_config_from_config_file_toml() {
+    let path = std::env::temp_dir().join("leptos_config_test.toml");
+    std::fs::write(
+        &path,
+        r#"
+[leptos-options]
+output-name = "test"
+site-addr = "127.0.0.1:4000"
+"#,
+    )
+    .unwrap();
+    let conf = get_config_from_config_file(&path).unwrap();
+    assert_eq!(conf.leptos_options.output_name.as_ref(), "test");
+    assert_eq!(
+        conf.leptos_options.site_addr,
+        "127.0.0.1:4000".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_get_config_from_config_file_json() {
+    let path = std::env::temp_dir().join("leptos_config_test.json");
+    std::fs::write(
+        &path,
+        r#"{"leptos-options": {"output-name": "test", "site-addr": "127.0.0.1:4001"}}"#,
+    )
+    .unwrap();
+    let conf = get_config_from_config_file(&path).unwrap();
+    assert_eq!(
+        conf.leptos_options.site_addr,
+        "127.0.0.1:4001".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_get_config_from_config_file_yaml() {
+    let path = std::env::temp_dir().join("leptos_config_test.yaml");
+    std::fs::write(
+        &path,
+        r#"
+leptos-options:
+  output-name: test
+  site-addr: "127.0.0.1:4002"
+"#,
+    )
+    .unwrap();
+    let conf = get_config_from_config_file(&path).unwrap();
+    assert_eq!(conf.leptos_options.output_name.as_ref(), "test");
+    assert_eq!(
+        conf.leptos_options.site_addr,
+        "127.0.0.1:4002".parse().unwrap()
+    );
+}
+
+#[test]
+fn test_get_config_from_config_file_unsupported_extension() {
+    let path = std::env::temp_dir().join("leptos_config_test.ini");
+    let err = get_config_from_config_file(&path).unwrap_err();
+    assert!(matches!(err, LeptosConfigError::UnsupportedConfigFormat(ref ext) if ext == "ini"));
+}
+
+#[test]
+fn test_get_config_from_config_file_missing_leptos_options_key() {
+    let path =
+        std::env::temp_dir().join("leptos_config_test_missing_key.yaml");
+    std::fs::write(&path, "some-other-key: 1\n").unwrap();
+    let err = get_config_from_config_file(&path).unwrap_err();
+    assert!(matches!(err, LeptosConfigError::MissingLeptosOptionsKey));
+}
+
+#[test]
+fn test_get_config_from_str_unknown_subtable_is_ignored() {
+    let text = r#"
+[package.metadata.leptos]
+output-name = "test"
+site-addr = "127.0.0.1:3000"
+
+[package.metadata.leptos.staging]
+site-addr = "10.0.0.1:9000"
+"#;
+    let conf = get_config_from_str(text).unwrap();
+    assert_eq!(conf.site_addr, "127.0.0.1:3000".parse().unwrap());
+}